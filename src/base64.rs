@@ -0,0 +1,118 @@
+use anyhow::Result;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
+/// Encodes `data` using the standard RFC 4648 alphabet with `=` padding.
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for group in data.chunks(3) {
+        let b0 = group[0];
+        let b1 = *group.get(1).unwrap_or(&0);
+        let b2 = *group.get(2).unwrap_or(&0);
+
+        let indices = [
+            b0 >> 2,
+            ((b0 & 0b0000_0011) << 4) | (b1 >> 4),
+            ((b1 & 0b0000_1111) << 2) | (b2 >> 6),
+            b2 & 0b0011_1111,
+        ];
+
+        out.push(ALPHABET[indices[0] as usize] as char);
+        out.push(ALPHABET[indices[1] as usize] as char);
+        out.push(if group.len() > 1 {
+            ALPHABET[indices[2] as usize] as char
+        } else {
+            '='
+        });
+        out.push(if group.len() > 2 {
+            ALPHABET[indices[3] as usize] as char
+        } else {
+            '='
+        });
+    }
+
+    out
+}
+
+/// Reverses [`encode`], rejecting non-alphabet characters and groups whose
+/// length isn't a multiple of 4.
+pub fn decode(input: &str) -> Result<Vec<u8>> {
+    let bytes = input.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(anyhow::anyhow!("Invalid base64 input length"));
+    }
+
+    let mut out = Vec::with_capacity(bytes.len() / 4 * 3);
+    for group in bytes.chunks(4) {
+        let pad_count = group.iter().rev().take_while(|&&b| b == PAD).count();
+        if pad_count > 2 {
+            return Err(anyhow::anyhow!("Invalid base64 padding"));
+        }
+
+        let mut indices = [0u8; 4];
+        for (i, &b) in group.iter().enumerate() {
+            if i >= group.len() - pad_count {
+                continue;
+            }
+            indices[i] = alphabet_index(b)?;
+        }
+
+        out.push((indices[0] << 2) | (indices[1] >> 4));
+        if pad_count < 2 {
+            out.push((indices[1] << 4) | (indices[2] >> 2));
+        }
+        if pad_count < 1 {
+            out.push((indices[2] << 6) | indices[3]);
+        }
+    }
+
+    Ok(out)
+}
+
+fn alphabet_index(byte: u8) -> Result<u8> {
+    ALPHABET
+        .iter()
+        .position(|&b| b == byte)
+        .map(|i| i as u8)
+        .ok_or_else(|| anyhow::anyhow!("Invalid base64 character: {}", byte as char))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_no_padding() {
+        assert_eq!(encode(b"Man"), "TWFu");
+    }
+
+    #[test]
+    fn test_encode_one_padding_char() {
+        assert_eq!(encode(b"Ma"), "TWE=");
+    }
+
+    #[test]
+    fn test_encode_two_padding_chars() {
+        assert_eq!(encode(b"M"), "TQ==");
+    }
+
+    #[test]
+    fn test_decode_roundtrip() {
+        let data = "This is where your secret message will be!".as_bytes();
+        let encoded = encode(data);
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_length() {
+        assert!(decode("abc").is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_character() {
+        assert!(decode("TWF!").is_err());
+    }
+}
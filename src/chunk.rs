@@ -1,35 +1,40 @@
 use std::fmt::Display;
 
 use anyhow::Result;
+use bytes::{Buf, BufMut, Bytes};
 
+use crate::base64;
 use crate::chunk_type::ChunkType;
+use crate::crc_algorithm::CrcAlgorithm;
 
 pub struct Chunk {
     /// The number of bytes in the chunk's data field
     length: usize,
     chunk_type: ChunkType,
-    chunk_data: Vec<u8>,
+    chunk_data: Bytes,
     // cyclic redundancy check calculated on the preceding bytes in the chunk, including the chunk type code and chunk data.
     crc: u32,
+    crc_algorithm: CrcAlgorithm,
 }
 
 impl Chunk {
     pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Self {
-        let chunk_type_vec = chunk_type
-            .bytes()
-            .into_iter()
-            .map(|b| b)
-            .collect::<Vec<u8>>();
+        Self::new_with_crc(chunk_type, data, CrcAlgorithm::default())
+    }
 
+    /// Builds a chunk whose CRC is computed with `algorithm` instead of the
+    /// PNG-spec default, for embedding data meant for non-standard readers.
+    pub fn new_with_crc(chunk_type: ChunkType, data: Vec<u8>, algorithm: CrcAlgorithm) -> Self {
         let size = data.len();
 
         let vec_to_check = [chunk_type.bytes().to_vec(), data.to_owned()].concat();
-        let check = crc::crc32::checksum_ieee(&vec_to_check);
+        let check = algorithm.checksum(&vec_to_check);
         Self {
             chunk_type,
-            chunk_data: data,
+            chunk_data: Bytes::from(data),
             length: size,
-            crc: check
+            crc: check,
+            crc_algorithm: algorithm,
         }
     }
     pub fn length(&self) -> u32 {
@@ -44,6 +49,33 @@ impl Chunk {
     pub fn crc(&self) -> u32 {
         self.crc
     }
+    /// Recomputes the checksum over the chunk type and current data and
+    /// compares it against the stored `crc`, catching drift after `chunk_data`
+    /// is mutated out from under it.
+    pub fn is_crc_valid(&self) -> bool {
+        let vec_to_check = [self.chunk_type.bytes().to_vec(), self.chunk_data.to_vec()].concat();
+        self.crc_algorithm.checksum(&vec_to_check) == self.crc
+    }
+
+    /// Refreshes the stored `crc` to match the current chunk type and data.
+    pub fn recompute_crc(&mut self) {
+        let vec_to_check = [self.chunk_type.bytes().to_vec(), self.chunk_data.to_vec()].concat();
+        self.crc = self.crc_algorithm.checksum(&vec_to_check);
+    }
+    /// Builds a chunk whose data is the base64 text-armored encoding of
+    /// `plaintext`, so the payload stays printable and survives tools that
+    /// expect ancillary chunk data to be ASCII.
+    pub fn new_armored(chunk_type: ChunkType, plaintext: &[u8]) -> Self {
+        Self::new(chunk_type, base64::encode(plaintext).into_bytes())
+    }
+
+    /// Reverses [`Chunk::new_armored`], decoding this chunk's data out of
+    /// base64.
+    pub fn data_decoded(&self) -> Result<Vec<u8>> {
+        let encoded = self.data_as_string()?;
+        base64::decode(&encoded)
+    }
+
     pub fn data_as_string(&self) -> Result<String> {
         let data = &self.chunk_data;
         let as_string = std::str::from_utf8(data)
@@ -52,28 +84,70 @@ impl Chunk {
         Ok(as_string)
     }
     pub fn as_bytes(&self) -> Vec<u8> {
-        todo!();
+        let mut buf = Vec::with_capacity(12 + self.chunk_data.len());
+        self.encode_buf(&mut buf);
+        buf
+    }
+
+    /// Serializes this chunk into `dst` in the canonical PNG layout:
+    /// big-endian length, chunk type, chunk data, then big-endian CRC.
+    pub fn encode_buf(&self, dst: &mut impl BufMut) {
+        dst.put_u32(self.length());
+        dst.put_slice(&self.chunk_type.bytes());
+        dst.put_slice(&self.chunk_data);
+        dst.put_u32(self.crc);
+    }
+
+    /// Parses a chunk out of `src`, advancing it past the whole chunk: the
+    /// 4-byte length field plus `length + 8` bytes of type, data and CRC.
+    pub fn decode_buf(src: &mut impl Buf) -> Result<Self> {
+        if src.remaining() < 4 {
+            return Err(anyhow::anyhow!("Not enough bytes to decode chunk length"));
+        }
+        let length = src.get_u32();
+
+        if src.remaining() < 4 {
+            return Err(anyhow::anyhow!("Not enough bytes to decode chunk type"));
+        }
+        let mut type_bytes: [u8; 4] = Default::default();
+        src.copy_to_slice(&mut type_bytes);
+        let chunk_type = ChunkType::try_from(type_bytes).map_err(anyhow::Error::msg)?;
+
+        if src.remaining() < length as usize + 4 {
+            return Err(anyhow::anyhow!("Not enough bytes to decode chunk data and CRC"));
+        }
+        let data = src.copy_to_bytes(length as usize);
+        let crc = src.get_u32();
+
+        let chunk = Self::new(chunk_type, data.to_vec());
+        if chunk.crc() != crc {
+            return Err(anyhow::anyhow!("Invalid chunk CRC length declared"));
+        }
+        Ok(chunk)
     }
 }
 
-impl TryFrom<&Vec<u8>> for Chunk {
-    type Error = &'static str;
+impl Chunk {
+    /// Same validation as the `TryFrom<&Vec<u8>>` impl, but checks the CRC
+    /// against `algorithm` instead of assuming the PNG-spec default.
+    pub fn try_from_with_crc(value: &[u8], algorithm: CrcAlgorithm) -> Result<Self, &'static str> {
+        if value.len() < 12 {
+            return Err("Chunk data too short");
+        }
 
-    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
         let mut byte_chunk_type : [u8; 4] = Default::default();
-        let value_slice = value.as_slice();
 
         let mut data_length_slice : [u8; 4] = Default::default();
         data_length_slice.copy_from_slice(&value[..4]);
         let data_length = bytes_to_number(&data_length_slice);
-        
-        byte_chunk_type.copy_from_slice(&value_slice[4..8]);
+
+        byte_chunk_type.copy_from_slice(&value[4..8]);
 
         let chunk_type = ChunkType::try_from(byte_chunk_type);
         if chunk_type.is_err() {
             return Err("Failed to build chunk type");
         }
-        let rest_bytes = &value_slice[8..];
+        let rest_bytes = &value[8..];
 
         let crc_starting_index = rest_bytes.len() - 4;
         let crc_bytes = rest_bytes[crc_starting_index..].to_vec();
@@ -82,7 +156,7 @@ impl TryFrom<&Vec<u8>> for Chunk {
 
         let rest_bytes = &rest_bytes[..crc_starting_index];
 
-        let chunk = Self::new(chunk_type.unwrap(), rest_bytes.to_vec());
+        let chunk = Self::new_with_crc(chunk_type.unwrap(), rest_bytes.to_vec(), algorithm);
 
         if chunk.length() != data_length {
             return Err("Invalid chunk length declared");
@@ -94,6 +168,14 @@ impl TryFrom<&Vec<u8>> for Chunk {
     }
 }
 
+impl TryFrom<&Vec<u8>> for Chunk {
+    type Error = &'static str;
+
+    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_with_crc(value, CrcAlgorithm::default())
+    }
+}
+
 impl Display for Chunk {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!("{}", self.data_as_string().unwrap_or("Invalid chunk".to_owned())))
@@ -213,6 +295,90 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_chunk_as_bytes() {
+        let chunk = testing_chunk();
+        let bytes = chunk.as_bytes();
+        let round_tripped = Chunk::try_from(&bytes).unwrap();
+        assert_eq!(round_tripped.crc(), chunk.crc());
+        assert_eq!(round_tripped.data(), chunk.data());
+    }
+
+    #[test]
+    fn test_chunk_encode_decode() {
+        let chunk = testing_chunk();
+        let mut buf = Vec::new();
+        chunk.encode_buf(&mut buf);
+
+        let mut src = buf.as_slice();
+        let decoded = Chunk::decode_buf(&mut src).unwrap();
+
+        assert_eq!(decoded.crc(), chunk.crc());
+        assert_eq!(decoded.data(), chunk.data());
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_chunk_decode_rejects_truncated_length() {
+        let mut src: &[u8] = &[0, 0, 0];
+        assert!(Chunk::decode_buf(&mut src).is_err());
+    }
+
+    #[test]
+    fn test_chunk_decode_rejects_truncated_data() {
+        let chunk = testing_chunk();
+        let mut buf = Vec::new();
+        chunk.encode_buf(&mut buf);
+        buf.truncate(buf.len() - 1);
+
+        let mut src = buf.as_slice();
+        assert!(Chunk::decode_buf(&mut src).is_err());
+    }
+
+    #[test]
+    fn test_chunk_armored_roundtrip() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let secret = "This is where your secret message will be!".as_bytes();
+        let chunk = Chunk::new_armored(chunk_type, secret);
+
+        assert!(chunk.data_as_string().unwrap().is_ascii());
+        assert_eq!(chunk.data_decoded().unwrap(), secret);
+    }
+
+    #[test]
+    fn test_chunk_from_short_bytes_is_err() {
+        let chunk = Chunk::try_from(&vec![0u8; 4]);
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    fn test_is_crc_valid() {
+        let chunk = testing_chunk();
+        assert!(chunk.is_crc_valid());
+    }
+
+    #[test]
+    fn test_recompute_crc_after_new_with_crc() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = "This is where your secret message will be!".as_bytes().to_vec();
+        let mut chunk = Chunk::new_with_crc(chunk_type, data, CrcAlgorithm::Castagnoli);
+
+        assert!(chunk.is_crc_valid());
+        chunk.recompute_crc();
+        assert!(chunk.is_crc_valid());
+    }
+
+    #[test]
+    fn test_try_from_with_crc_matches_algorithm_used_to_encode() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = "This is where your secret message will be!".as_bytes().to_vec();
+        let chunk = Chunk::new_with_crc(chunk_type, data, CrcAlgorithm::Castagnoli);
+        let bytes = chunk.as_bytes();
+
+        assert!(Chunk::try_from_with_crc(&bytes, CrcAlgorithm::Castagnoli).is_ok());
+        assert!(Chunk::try_from_with_crc(&bytes, CrcAlgorithm::IsoHdlc).is_err());
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
@@ -0,0 +1,161 @@
+use std::fmt::Display;
+use std::io::Read;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+
+/// The 8-byte sequence every PNG file starts with.
+pub const PNG_SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+#[derive(Debug)]
+pub enum ChunkReadError {
+    Io(std::io::Error),
+    InvalidSignature,
+    InvalidChunkType,
+    CrcMismatch { expected: u32, actual: u32 },
+}
+
+impl Display for ChunkReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChunkReadError::Io(e) => write!(f, "I/O error while reading chunk: {e}"),
+            ChunkReadError::InvalidSignature => write!(f, "Invalid PNG signature"),
+            ChunkReadError::InvalidChunkType => write!(f, "Invalid chunk type"),
+            ChunkReadError::CrcMismatch { expected, actual } => write!(
+                f,
+                "Chunk CRC mismatch: declared {expected}, computed {actual}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ChunkReadError {}
+
+impl From<std::io::Error> for ChunkReadError {
+    fn from(e: std::io::Error) -> Self {
+        ChunkReadError::Io(e)
+    }
+}
+
+/// Lazily parses a stream of `Chunk`s out of a `Read`, without buffering the
+/// whole file in memory. Validates the 8-byte PNG signature once on
+/// construction, then yields one chunk at a time until the reader is
+/// exhausted.
+pub struct ChunkReader<R: Read> {
+    reader: R,
+    done: bool,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(mut reader: R) -> Result<Self, ChunkReadError> {
+        let mut signature: [u8; 8] = Default::default();
+        reader.read_exact(&mut signature)?;
+        if signature != PNG_SIGNATURE {
+            return Err(ChunkReadError::InvalidSignature);
+        }
+        Ok(Self { reader, done: false })
+    }
+
+    fn read_chunk(&mut self, length: u32) -> Result<Chunk, ChunkReadError> {
+        let mut type_bytes: [u8; 4] = Default::default();
+        self.reader.read_exact(&mut type_bytes)?;
+        let chunk_type =
+            ChunkType::try_from(type_bytes).map_err(|_| ChunkReadError::InvalidChunkType)?;
+
+        let mut data = vec![0u8; length as usize];
+        self.reader.read_exact(&mut data)?;
+
+        let mut crc_bytes: [u8; 4] = Default::default();
+        self.reader.read_exact(&mut crc_bytes)?;
+        let crc = u32::from_be_bytes(crc_bytes);
+
+        let chunk = Chunk::new(chunk_type, data);
+        if chunk.crc() != crc {
+            return Err(ChunkReadError::CrcMismatch {
+                expected: crc,
+                actual: chunk.crc(),
+            });
+        }
+        Ok(chunk)
+    }
+}
+
+impl<R: Read> Iterator for ChunkReader<R> {
+    type Item = Result<Chunk, ChunkReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut length_bytes: [u8; 4] = Default::default();
+        match self.reader.read(&mut length_bytes[..1]) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e.into()));
+            }
+        }
+        if let Err(e) = self.reader.read_exact(&mut length_bytes[1..]) {
+            self.done = true;
+            return Some(Err(e.into()));
+        }
+        let length = u32::from_be_bytes(length_bytes);
+
+        match self.read_chunk(length) {
+            Ok(chunk) => Some(Ok(chunk)),
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn png_bytes_with_chunks(chunks: &[Chunk]) -> Vec<u8> {
+        let mut bytes = PNG_SIGNATURE.to_vec();
+        for chunk in chunks {
+            bytes.extend(chunk.as_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_all_chunks() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, "hello".as_bytes().to_vec());
+        let bytes = png_bytes_with_chunks(&[chunk]);
+
+        let reader = ChunkReader::new(bytes.as_slice()).unwrap();
+        let chunks: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].data_as_string().unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_chunk_reader_rejects_invalid_signature() {
+        let bytes = vec![0u8; 8];
+        let result = ChunkReader::new(bytes.as_slice());
+        assert!(matches!(result, Err(ChunkReadError::InvalidSignature)));
+    }
+
+    #[test]
+    fn test_chunk_reader_rejects_truncated_chunk() {
+        let mut bytes = PNG_SIGNATURE.to_vec();
+        bytes.extend([0, 0, 0, 10]); // declares 10 bytes of data, but supplies none
+        let mut reader = ChunkReader::new(bytes.as_slice()).unwrap();
+
+        let result = reader.next().unwrap();
+        assert!(matches!(result, Err(ChunkReadError::Io(_))));
+    }
+}
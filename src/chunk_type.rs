@@ -1,6 +1,7 @@
 use std::{str::FromStr, fmt::Display};
 
 use anyhow::Result;
+use bytes::{Buf, BufMut};
 
 
 #[derive(Debug, PartialEq, Eq)]
@@ -47,6 +48,78 @@ impl ChunkType {
     pub fn is_safe_to_copy(&self) -> bool {
         !fifth_bit_is_zero(&self.bytes[3])
     }
+
+    pub fn encode_buf(&self, dst: &mut impl BufMut) {
+        dst.put_slice(&self.bytes());
+    }
+
+    pub fn decode_buf(src: &mut impl Buf) -> Result<Self> {
+        if src.remaining() < 4 {
+            return Err(anyhow::anyhow!("Not enough bytes to decode a chunk type"));
+        }
+        let mut bytes: [u8; 4] = Default::default();
+        src.copy_to_slice(&mut bytes);
+        Self::try_from(bytes).map_err(anyhow::Error::msg)
+    }
+
+    /// Starts a [`ChunkTypeBuilder`] for `base`, a 4-letter ASCII type code.
+    pub fn builder(base: &str) -> Result<ChunkTypeBuilder, &'static str> {
+        ChunkTypeBuilder::new(base)
+    }
+}
+
+fn set_case(byte: &mut u8, uppercase: bool) {
+    let fifth_bit: u8 = 0b0010_0000;
+    if uppercase {
+        *byte &= !fifth_bit;
+    } else {
+        *byte |= fifth_bit;
+    }
+}
+
+/// Builds a spec-correct [`ChunkType`] from a 4-letter base and the
+/// ancillary/private/safe-to-copy bits, setting each bit by flipping the
+/// letter's case instead of requiring callers to hand-craft ASCII.
+pub struct ChunkTypeBuilder {
+    base: [u8; 4],
+}
+
+impl ChunkTypeBuilder {
+    pub fn new(base: &str) -> Result<Self, &'static str> {
+        let bytes = base.as_bytes();
+        if bytes.len() != 4 {
+            return Err("Invalid size");
+        }
+        if !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return Err("Invalid chunk");
+        }
+
+        let mut base_bytes: [u8; 4] = Default::default();
+        base_bytes.copy_from_slice(bytes);
+        set_case(&mut base_bytes[2], true); // reserved bit must stay valid
+        Ok(Self { base: base_bytes })
+    }
+
+    pub fn critical(mut self, critical: bool) -> Self {
+        set_case(&mut self.base[0], critical);
+        self
+    }
+
+    pub fn public(mut self, public: bool) -> Self {
+        set_case(&mut self.base[1], public);
+        self
+    }
+
+    pub fn safe_to_copy(mut self, safe_to_copy: bool) -> Self {
+        set_case(&mut self.base[3], !safe_to_copy);
+        self
+    }
+
+    pub fn build(self) -> ChunkType {
+        ChunkType {
+            bytes: self.base.to_vec(),
+        }
+    }
 }
 
 impl TryFrom<[u8; 4]> for ChunkType {
@@ -64,7 +137,7 @@ impl FromStr for ChunkType {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let vec = s.as_bytes().to_vec();
-        if vec.len() > 4 {
+        if vec.len() != 4 {
             return Err("Invalid size");
         }
         let chunk = Self{
@@ -77,12 +150,12 @@ impl FromStr for ChunkType {
     }
 }
 
-impl From<String> for ChunkType {
-    fn from(s: String) -> Self {
-        Self{
-            bytes: s.as_bytes().to_vec(),
-        }
-     }
+impl TryFrom<String> for ChunkType {
+    type Error = &'static str;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
 }
 
 impl Display for ChunkType {
@@ -183,6 +256,59 @@ mod tests {
         assert_eq!(&chunk.to_string(), "RuSt");
     }
 
+    #[test]
+    pub fn test_chunk_type_encode_decode() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let mut buf = Vec::new();
+        chunk_type.encode_buf(&mut buf);
+
+        let mut src = buf.as_slice();
+        let decoded = ChunkType::decode_buf(&mut src).unwrap();
+
+        assert_eq!(chunk_type, decoded);
+    }
+
+    #[test]
+    pub fn test_from_str_rejects_short_input() {
+        assert!(ChunkType::from_str("Rus").is_err());
+    }
+
+    #[test]
+    pub fn test_try_from_string_rejects_long_input() {
+        assert!(ChunkType::try_from(String::from("RuSty")).is_err());
+    }
+
+    #[test]
+    pub fn test_builder_default_flags() {
+        let chunk = ChunkTypeBuilder::new("rust").unwrap().build();
+        assert!(!chunk.is_critical());
+        assert!(!chunk.is_public());
+        assert!(chunk.is_reserved_bit_valid());
+        assert!(chunk.is_safe_to_copy());
+        assert!(chunk.is_valid());
+    }
+
+    #[test]
+    pub fn test_builder_sets_flags_via_case() {
+        let chunk = ChunkTypeBuilder::new("rust")
+            .unwrap()
+            .critical(true)
+            .public(true)
+            .safe_to_copy(true)
+            .build();
+
+        assert!(chunk.is_critical());
+        assert!(chunk.is_public());
+        assert!(chunk.is_safe_to_copy());
+        assert!(chunk.is_valid());
+        assert_eq!(&chunk.to_string(), "RUSt");
+    }
+
+    #[test]
+    pub fn test_builder_rejects_wrong_length() {
+        assert!(ChunkTypeBuilder::new("rus").is_err());
+    }
+
     #[test]
     pub fn test_chunk_type_trait_impls() {
         let chunk_type_1: ChunkType = TryFrom::try_from([82, 117, 83, 116]).unwrap();
@@ -0,0 +1,137 @@
+use anyhow::Result;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+
+/// A value that can serialize itself into a caller-owned buffer, reporting
+/// its exact encoded size up front so the buffer can be sized once with no
+/// reallocation.
+pub trait Encode {
+    fn encoded_len(&self) -> usize;
+    fn encode(&self, out: &mut Vec<u8>);
+}
+
+/// The inverse of [`Encode`]: parses a value off the front of `input`,
+/// advancing the slice reference past the bytes it consumed so further
+/// values can be decoded in sequence.
+pub trait Decode<'a>: Sized {
+    fn decode(input: &mut &'a [u8]) -> Result<Self>;
+}
+
+impl Encode for ChunkType {
+    fn encoded_len(&self) -> usize {
+        4
+    }
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.bytes());
+    }
+}
+
+impl<'a> Decode<'a> for ChunkType {
+    fn decode(input: &mut &'a [u8]) -> Result<Self> {
+        if input.len() < 4 {
+            return Err(anyhow::anyhow!("Not enough bytes to decode a chunk type"));
+        }
+        let (bytes, rest) = input.split_at(4);
+        let chunk_type = ChunkType::try_from(<[u8; 4]>::try_from(bytes)?).map_err(anyhow::Error::msg)?;
+        *input = rest;
+        Ok(chunk_type)
+    }
+}
+
+impl Encode for Chunk {
+    fn encoded_len(&self) -> usize {
+        12 + self.data().len()
+    }
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.length().to_be_bytes());
+        self.chunk_type().encode(out);
+        out.extend_from_slice(self.data());
+        out.extend_from_slice(&self.crc().to_be_bytes());
+    }
+}
+
+impl<'a> Decode<'a> for Chunk {
+    fn decode(input: &mut &'a [u8]) -> Result<Self> {
+        if input.len() < 4 {
+            return Err(anyhow::anyhow!("Not enough bytes to decode a chunk length"));
+        }
+        let (length_bytes, rest) = input.split_at(4);
+        let length = u32::from_be_bytes(length_bytes.try_into()?);
+        *input = rest;
+
+        let chunk_type = ChunkType::decode(input)?;
+
+        if input.len() < length as usize + 4 {
+            return Err(anyhow::anyhow!("Not enough bytes to decode chunk data and CRC"));
+        }
+        let (data, rest) = input.split_at(length as usize);
+        *input = rest;
+
+        let (crc_bytes, rest) = input.split_at(4);
+        let crc = u32::from_be_bytes(crc_bytes.try_into()?);
+        *input = rest;
+
+        let chunk = Chunk::new(chunk_type, data.to_vec());
+        if chunk.crc() != crc {
+            return Err(anyhow::anyhow!("Invalid chunk CRC length declared"));
+        }
+        Ok(chunk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_chunk_type_encoded_len() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        assert_eq!(chunk_type.encoded_len(), 4);
+    }
+
+    #[test]
+    fn test_chunk_encoded_len() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, "hello".as_bytes().to_vec());
+        assert_eq!(chunk.encoded_len(), 12 + 5);
+    }
+
+    #[test]
+    fn test_chunk_encode_decode_roundtrip() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, "hello".as_bytes().to_vec());
+
+        let mut out = Vec::with_capacity(chunk.encoded_len());
+        chunk.encode(&mut out);
+
+        let mut input = out.as_slice();
+        let decoded = Chunk::decode(&mut input).unwrap();
+
+        assert_eq!(decoded.crc(), chunk.crc());
+        assert_eq!(decoded.data(), chunk.data());
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn test_nested_chunks_decode_in_sequence() {
+        let first = Chunk::new(ChunkType::from_str("frSa").unwrap(), "one".as_bytes().to_vec());
+        let second = Chunk::new(ChunkType::from_str("frSb").unwrap(), "two".as_bytes().to_vec());
+
+        let mut container_data = Vec::with_capacity(first.encoded_len() + second.encoded_len());
+        first.encode(&mut container_data);
+        second.encode(&mut container_data);
+        let container = Chunk::new(ChunkType::from_str("frSc").unwrap(), container_data);
+
+        let mut input = container.data();
+        let decoded_first = Chunk::decode(&mut input).unwrap();
+        let decoded_second = Chunk::decode(&mut input).unwrap();
+
+        assert_eq!(decoded_first.data(), first.data());
+        assert_eq!(decoded_second.data(), second.data());
+        assert!(input.is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+/// The CRC-32 variant used to checksum a chunk's type and data.
+///
+/// The PNG spec mandates [`CrcAlgorithm::IsoHdlc`]; the other variants exist
+/// for readers that deliberately expect a different polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrcAlgorithm {
+    /// CRC-32/ISO-HDLC, the polynomial the PNG spec requires.
+    #[default]
+    IsoHdlc,
+    /// CRC-32C (Castagnoli).
+    Castagnoli,
+    /// CRC-32K (Koopman).
+    Koopman,
+}
+
+impl CrcAlgorithm {
+    pub fn checksum(&self, data: &[u8]) -> u32 {
+        match self {
+            CrcAlgorithm::IsoHdlc => crc::crc32::checksum_ieee(data),
+            CrcAlgorithm::Castagnoli => crc::crc32::checksum_castagnoli(data),
+            CrcAlgorithm::Koopman => crc::crc32::checksum_koopman(data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_algorithm_is_iso_hdlc() {
+        assert_eq!(CrcAlgorithm::default(), CrcAlgorithm::IsoHdlc);
+    }
+
+    #[test]
+    fn test_different_algorithms_can_disagree() {
+        let data = b"RuStThis is where your secret message will be!";
+        assert_ne!(
+            CrcAlgorithm::IsoHdlc.checksum(data),
+            CrcAlgorithm::Castagnoli.checksum(data)
+        );
+    }
+}